@@ -0,0 +1,112 @@
+use std::path::{Path, PathBuf};
+
+use url::Url;
+
+use super::{Canvas, FileNode, GenericNodeInfo, Node, NodeId};
+
+/// Something a [Canvas] points at outside of itself: a vault file, a group background
+/// image, or an external URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dependency {
+    File {
+        node_id: NodeId,
+        path: PathBuf,
+        subpath: Option<String>,
+    },
+    Url {
+        node_id: NodeId,
+        url: Url,
+    },
+    Background {
+        node_id: NodeId,
+        path: PathBuf,
+    },
+}
+
+/// Joins `path` onto `vault_root`, unless `path` is already absolute.
+///
+/// `vault_root` is absolutized against the current directory first, so the result is an
+/// absolute path whenever the current directory can be read (otherwise a relative
+/// `vault_root` is joined as-is): resolving an already-resolved path is then a no-op instead of
+/// double-prefixing, since `path.is_absolute()` is unambiguous (unlike checking whether
+/// `path` merely starts with `vault_root`, which misfires for vault-relative paths that
+/// happen to share a leading component with the root's name).
+fn resolve_against(vault_root: &Path, path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_path_buf();
+    }
+    let vault_root = if vault_root.is_absolute() {
+        vault_root.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map(|cwd| cwd.join(vault_root))
+            .unwrap_or_else(|_| vault_root.to_path_buf())
+    };
+    vault_root.join(path)
+}
+
+impl Canvas {
+    /// Every file, URL, and group background referenced by this canvas.
+    pub fn dependencies(&self) -> Vec<Dependency> {
+        let mut out = Vec::new();
+        for node in self.nodes() {
+            match node {
+                Node::File(f) => out.push(Dependency::File {
+                    node_id: f.id().clone(),
+                    path: f.file().to_path_buf(),
+                    subpath: f.subpath().map(str::to_string),
+                }),
+                Node::Link(l) => out.push(Dependency::Url {
+                    node_id: l.id().clone(),
+                    url: l.url().clone(),
+                }),
+                Node::Group(g) => {
+                    if let Some(path) = g.background() {
+                        out.push(Dependency::Background {
+                            node_id: g.id().clone(),
+                            path: path.to_path_buf(),
+                        });
+                    }
+                }
+                Node::Text(_) => {}
+            }
+        }
+        out
+    }
+
+    /// Rewrite every relative `FileNode`/background path against `vault_root`.
+    ///
+    /// Safe to call more than once (with the same `vault_root`) without double-prefixing
+    /// already-resolved paths.
+    pub fn resolve_paths(&mut self, vault_root: &Path) {
+        for node in self.nodes_mut() {
+            match node {
+                Node::File(f) => {
+                    let file = f.file_mut();
+                    *file = resolve_against(vault_root, file);
+                }
+                Node::Group(g) => {
+                    if let Some(background) = g.background_mut() {
+                        *background = resolve_against(vault_root, background);
+                    }
+                }
+                Node::Text(_) | Node::Link(_) => {}
+            }
+        }
+    }
+
+    /// The `FileNode`s whose referenced file does not exist under `vault_root`.
+    ///
+    /// Works whether or not [Canvas::resolve_paths] was already called with this
+    /// `vault_root`: an already-resolved path is checked as-is rather than re-joined.
+    pub fn missing_files(&self, vault_root: &Path) -> Vec<&FileNode> {
+        self.nodes()
+            .iter()
+            .filter_map(|n| match n {
+                Node::File(f) => Some(f),
+                _ => None,
+            })
+            .filter(|f| !resolve_against(vault_root, f.file()).exists())
+            .collect()
+    }
+}
@@ -0,0 +1,108 @@
+use super::{Canvas, Dimensions, GenericNodeInfo, Location, Node, NodeId, PxCoord, PxLength};
+
+/// An axis-aligned rectangle in canvas space, derived from a node's
+/// [Location] and [Dimensions].
+///
+/// Containment and intersection are both inclusive on the edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    x0: PxCoord,
+    y0: PxCoord,
+    x1: PxCoord,
+    y1: PxCoord,
+}
+
+impl Rect {
+    pub fn new(location: Location, dimensions: Dimensions) -> Self {
+        let x0 = location.x;
+        let y0 = location.y;
+        Self {
+            x0,
+            y0,
+            x1: x0 + dimensions.width as PxCoord,
+            y1: y0 + dimensions.height as PxCoord,
+        }
+    }
+
+    pub fn x0(&self) -> PxCoord {
+        self.x0
+    }
+
+    pub fn y0(&self) -> PxCoord {
+        self.y0
+    }
+
+    pub fn x1(&self) -> PxCoord {
+        self.x1
+    }
+
+    pub fn y1(&self) -> PxCoord {
+        self.y1
+    }
+
+    /// The x coordinate of the rectangle's origin (top-left corner).
+    pub fn x(&self) -> PxCoord {
+        self.x0
+    }
+
+    /// The y coordinate of the rectangle's origin (top-left corner).
+    pub fn y(&self) -> PxCoord {
+        self.y0
+    }
+
+    pub fn width(&self) -> PxLength {
+        (self.x1 - self.x0) as PxLength
+    }
+
+    pub fn height(&self) -> PxLength {
+        (self.y1 - self.y0) as PxLength
+    }
+
+    /// Whether `other` lies entirely within (or exactly on the boundary of) `self`.
+    pub fn contains(&self, other: &Rect) -> bool {
+        self.x0 <= other.x0 && self.y0 <= other.y0 && self.x1 >= other.x1 && self.y1 >= other.y1
+    }
+
+    /// Whether `self` and `other` share at least one point.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.x0 <= other.x1 && other.x0 <= self.x1 && self.y0 <= other.y1 && other.y0 <= self.y1
+    }
+
+    /// The smallest rectangle containing both `self` and `other`.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            x0: self.x0.min(other.x0),
+            y0: self.y0.min(other.y0),
+            x1: self.x1.max(other.x1),
+            y1: self.y1.max(other.y1),
+        }
+    }
+}
+
+impl Canvas {
+    /// The smallest [Rect] enclosing every node, or `None` if the canvas has no nodes.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.nodes()
+            .iter()
+            .map(Node::rect)
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// The nodes geometrically enclosed by the [GroupNode](super::GroupNode) with id `group_id`.
+    ///
+    /// JSON Canvas groups have no explicit child list; membership is defined purely by
+    /// whether a node's rectangle sits within the group's rectangle.
+    pub fn group_members(&self, group_id: &NodeId) -> Vec<&Node> {
+        let Some(group_rect) = self.nodes().iter().find_map(|n| match n {
+            Node::Group(g) if g.id() == group_id => Some(n.rect()),
+            _ => None,
+        }) else {
+            return Vec::new();
+        };
+
+        self.nodes()
+            .iter()
+            .filter(|n| n.id() != group_id && group_rect.contains(&n.rect()))
+            .collect()
+    }
+}
@@ -0,0 +1,91 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{Canvas, Edge, GenericNodeInfo, Node, NodeId};
+
+impl Canvas {
+    fn node_by_id(&self, id: &NodeId) -> Option<&Node> {
+        self.nodes().iter().find(|n| n.id() == id)
+    }
+
+    /// Every edge touching `id`, paired with the node at its other end, in either direction.
+    pub fn neighbors<'a>(&'a self, id: &'a NodeId) -> impl Iterator<Item = (&'a Edge, &'a Node)> {
+        self.edges.iter().filter_map(move |edge| {
+            let other = if edge.from_node() == id {
+                Some(edge.to_node())
+            } else if edge.to_node() == id {
+                Some(edge.from_node())
+            } else {
+                None
+            };
+            other.and_then(|other| self.node_by_id(other)).map(|n| (edge, n))
+        })
+    }
+
+    /// Edges leaving `id`, paired with the node each points to.
+    pub fn outgoing<'a>(&'a self, id: &'a NodeId) -> impl Iterator<Item = (&'a Edge, &'a Node)> {
+        self.edges
+            .iter()
+            .filter(move |e| e.from_node() == id)
+            .filter_map(move |e| self.node_by_id(e.to_node()).map(|n| (e, n)))
+    }
+
+    /// Edges arriving at `id`, paired with the node each comes from.
+    pub fn incoming<'a>(&'a self, id: &'a NodeId) -> impl Iterator<Item = (&'a Edge, &'a Node)> {
+        self.edges
+            .iter()
+            .filter(move |e| e.to_node() == id)
+            .filter_map(move |e| self.node_by_id(e.from_node()).map(|n| (e, n)))
+    }
+
+    /// Every edge directly connecting `a` and `b`, in either direction.
+    pub fn edges_between<'a>(&'a self, a: &'a NodeId, b: &'a NodeId) -> impl Iterator<Item = &'a Edge> {
+        self.edges.iter().filter(move |e| {
+            (e.from_node() == a && e.to_node() == b) || (e.from_node() == b && e.to_node() == a)
+        })
+    }
+
+    /// The connected components of the canvas, treating edges as undirected.
+    ///
+    /// Edges referencing an [unknown node](Canvas::unknown_nodes) are skipped rather
+    /// than causing a panic.
+    pub fn connected_components(&self) -> Vec<HashSet<&NodeId>> {
+        let unknown = self.unknown_nodes();
+        let nodes = self.nodes();
+        let index_of: HashMap<&str, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (n.id().as_str(), i))
+            .collect();
+
+        let mut parent: Vec<usize> = (0..nodes.len()).collect();
+
+        fn find(parent: &mut [usize], x: usize) -> usize {
+            if parent[x] != x {
+                parent[x] = find(parent, parent[x]);
+            }
+            parent[x]
+        }
+
+        for edge in &self.edges {
+            if unknown.contains(edge.from_node().as_str()) || unknown.contains(edge.to_node().as_str()) {
+                continue;
+            }
+            if let (Some(&a), Some(&b)) = (
+                index_of.get(edge.from_node().as_str()),
+                index_of.get(edge.to_node().as_str()),
+            ) {
+                let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+                if ra != rb {
+                    parent[rb] = ra;
+                }
+            }
+        }
+
+        let mut groups: HashMap<usize, HashSet<&NodeId>> = HashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            let root = find(&mut parent, i);
+            groups.entry(root).or_default().insert(node.id());
+        }
+        groups.into_values().collect()
+    }
+}
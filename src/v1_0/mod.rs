@@ -8,6 +8,14 @@ pub use hex_color::HexColor;
 use serde::{Deserialize, Deserializer, Serialize};
 use url::Url;
 
+mod dependencies;
+mod geometry;
+mod graph;
+mod validation;
+pub use dependencies::Dependency;
+pub use geometry::Rect;
+pub use validation::ValidationIssue;
+
 pub type NodeId = String;
 pub type EdgeId = String;
 pub type PxCoord = i64;
@@ -114,6 +122,11 @@ pub trait GenericNodeInfo {
     fn location(&self) -> &Location;
     fn dimensions(&self) -> &Dimensions;
     fn color(&self) -> &Option<Color>;
+
+    /// The rectangle this node occupies on the canvas.
+    fn rect(&self) -> Rect {
+        Rect::new(*self.location(), *self.dimensions())
+    }
 }
 
 impl GenericNodeInfo for GenericNode {
@@ -190,6 +203,10 @@ impl FileNode {
         &self.file
     }
 
+    pub fn file_mut(&mut self) -> &mut PathBuf {
+        &mut self.file
+    }
+
     pub fn subpath(&self) -> Option<&str> {
         self.subpath.as_deref()
     }
@@ -257,6 +274,10 @@ impl GroupNode {
         self.background.as_deref()
     }
 
+    pub fn background_mut(&mut self) -> Option<&mut PathBuf> {
+        self.background.as_mut()
+    }
+
     pub fn background_style(&self) -> Option<BackgroundStyle> {
         self.background_style
     }
@@ -444,7 +465,18 @@ impl Default for Color {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl Color {
+    /// The concrete RGB value this color represents, resolving [PresetColor] variants
+    /// to their fixed hex value.
+    pub fn resolve(&self) -> HexColor {
+        match self {
+            Self::Preset(preset) => preset.to_hex(),
+            Self::Hex(hex) => *hex,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PresetColor {
     Red = 1,
     Orange = 2,
@@ -454,6 +486,50 @@ pub enum PresetColor {
     Purple = 6,
 }
 
+impl PresetColor {
+    /// The concrete RGB value Obsidian renders this preset as.
+    pub fn to_hex(&self) -> HexColor {
+        match self {
+            Self::Red => HexColor::rgb(0xfb, 0x46, 0x4c),
+            Self::Orange => HexColor::rgb(0xe9, 0x97, 0x3f),
+            Self::Yellow => HexColor::rgb(0xe0, 0xde, 0x71),
+            Self::Green => HexColor::rgb(0x44, 0xcf, 0x6e),
+            Self::Cyan => HexColor::rgb(0x53, 0xdf, 0xdd),
+            Self::Purple => HexColor::rgb(0xa8, 0x82, 0xff),
+        }
+    }
+}
+
+/// JSON Canvas encodes presets as the numeric strings `"1"`-`"6"`, not the variant name.
+impl Serialize for PresetColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&(*self as u8).to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PresetColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "1" => Ok(Self::Red),
+            "2" => Ok(Self::Orange),
+            "3" => Ok(Self::Yellow),
+            "4" => Ok(Self::Green),
+            "5" => Ok(Self::Cyan),
+            "6" => Ok(Self::Purple),
+            other => Err(serde::de::Error::custom(format!(
+                "invalid preset color index `{other}`, expected \"1\"..\"6\""
+            ))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -513,4 +589,263 @@ mod tests {
         let s = serde_json::to_string_pretty(&canvas).unwrap();
         let _canvas2: Canvas = serde_json::from_str(&s).unwrap();
     }
+
+    #[test]
+    fn group_members_and_bounding_box() {
+        let mut canvas = Canvas::default();
+        let n = canvas.nodes_mut();
+
+        n.push(
+            GroupNode::new(
+                "group".to_string(),
+                Location::new(0, 0),
+                Dimensions::new(100, 100),
+                None,
+                None,
+                None,
+                None,
+            )
+            .into(),
+        );
+        n.push(
+            TextNode::new(
+                "inside".to_string(),
+                Location::new(10, 10),
+                Dimensions::new(10, 10),
+                None,
+                "I'm in the group".to_string(),
+            )
+            .into(),
+        );
+        n.push(
+            TextNode::new(
+                "outside".to_string(),
+                Location::new(200, 200),
+                Dimensions::new(10, 10),
+                None,
+                "I'm not".to_string(),
+            )
+            .into(),
+        );
+
+        let members = canvas.group_members(&"group".to_string());
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].id(), "inside");
+
+        let bbox = canvas.bounding_box().unwrap();
+        assert_eq!((bbox.x0(), bbox.y0()), (0, 0));
+        assert_eq!((bbox.x1(), bbox.y1()), (210, 210));
+        assert_eq!((bbox.x(), bbox.y()), (0, 0));
+        assert_eq!((bbox.width(), bbox.height()), (210, 210));
+
+        assert_eq!(Canvas::default().bounding_box(), None);
+    }
+
+    #[test]
+    fn preset_color_serializes_as_numeric_string() {
+        let s = serde_json::to_string(&PresetColor::Purple).unwrap();
+        assert_eq!(s, "\"6\"");
+
+        let back: PresetColor = serde_json::from_str(&s).unwrap();
+        assert_eq!(back, PresetColor::Purple);
+
+        assert!(serde_json::from_str::<PresetColor>("\"Purple\"").is_err());
+    }
+
+    #[test]
+    fn color_resolve() {
+        assert_eq!(
+            Color::Preset(PresetColor::Red).resolve(),
+            HexColor::rgb(0xfb, 0x46, 0x4c)
+        );
+        let hex = HexColor::rgb(1, 2, 3);
+        assert_eq!(Color::Hex(hex).resolve(), hex);
+    }
+
+    #[test]
+    fn dependencies_and_missing_files() {
+        use std::path::PathBuf;
+
+        let mut canvas = Canvas::default();
+        canvas.nodes_mut().push(
+            FileNode::new(
+                "myfile".to_string(),
+                Location::new(0, 0),
+                Dimensions::new(10, 10),
+                None,
+                PathBuf::from("notes/missing.md"),
+                None,
+            )
+            .into(),
+        );
+
+        let deps = canvas.dependencies();
+        assert_eq!(deps.len(), 1);
+        assert!(matches!(&deps[0], Dependency::File { node_id, .. } if node_id == "myfile"));
+
+        let vault_root = PathBuf::from("/does/not/exist");
+        canvas.resolve_paths(&vault_root);
+        let missing = canvas.missing_files(&vault_root);
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].file(), vault_root.join("notes/missing.md"));
+    }
+
+    #[test]
+    fn missing_files_with_relative_vault_root_is_not_double_prefixed() {
+        use std::fs;
+
+        let vault_root = PathBuf::from("target/tmp_vault_relative_test");
+        let _ = fs::remove_dir_all(&vault_root);
+        fs::create_dir_all(vault_root.join("notes")).unwrap();
+        fs::write(vault_root.join("notes/real.md"), "hi").unwrap();
+
+        let mut canvas = Canvas::default();
+        canvas.nodes_mut().push(
+            FileNode::new(
+                "myfile".to_string(),
+                Location::new(0, 0),
+                Dimensions::new(10, 10),
+                None,
+                PathBuf::from("notes/real.md"),
+                None,
+            )
+            .into(),
+        );
+
+        canvas.resolve_paths(&vault_root);
+        let missing = canvas.missing_files(&vault_root);
+
+        fs::remove_dir_all(&vault_root).ok();
+
+        assert!(
+            missing.is_empty(),
+            "a real file should not be reported missing after resolve_paths + \
+             missing_files share a relative vault_root"
+        );
+    }
+
+    fn text_node(id: &str) -> Node {
+        TextNode::new(
+            id.to_string(),
+            Location::new(0, 0),
+            Dimensions::new(10, 10),
+            None,
+            id.to_string(),
+        )
+        .into()
+    }
+
+    #[test]
+    fn graph_traversal() {
+        let mut canvas = Canvas::default();
+        for id in ["a", "b", "c", "d"] {
+            canvas.nodes_mut().push(text_node(id));
+        }
+        canvas.edges_mut().push(Edge::new(
+            "ab".to_string(),
+            Terminal::new("a".to_string(), None, None),
+            Terminal::new("b".to_string(), None, None),
+            None,
+            None,
+        ));
+        canvas.edges_mut().push(Edge::new(
+            "bc".to_string(),
+            Terminal::new("b".to_string(), None, None),
+            Terminal::new("c".to_string(), None, None),
+            None,
+            None,
+        ));
+        canvas.edges_mut().push(Edge::new(
+            "dangling".to_string(),
+            Terminal::new("d".to_string(), None, None),
+            Terminal::new("ghost".to_string(), None, None),
+            None,
+            None,
+        ));
+
+        let a = "a".to_string();
+        let b = "b".to_string();
+        let c = "c".to_string();
+
+        assert_eq!(canvas.outgoing(&a).count(), 1);
+        assert_eq!(canvas.incoming(&b).count(), 1);
+        assert_eq!(canvas.neighbors(&b).count(), 2);
+        assert_eq!(canvas.edges_between(&a, &b).count(), 1);
+        assert_eq!(canvas.edges_between(&a, &c).count(), 0);
+
+        let components = canvas.connected_components();
+        assert_eq!(components.len(), 2);
+        let sizes: HashSet<usize> = components.iter().map(|c| c.len()).collect();
+        assert_eq!(sizes, HashSet::from([3, 1]));
+    }
+
+    #[test]
+    fn validate_finds_issues() {
+        let mut canvas = Canvas::default();
+        canvas.nodes_mut().push(
+            FileNode::new(
+                "empty-path".to_string(),
+                Location::new(0, 0),
+                Dimensions::new(0, 10),
+                None,
+                PathBuf::new(),
+                None,
+            )
+            .into(),
+        );
+        canvas.nodes_mut().push(text_node("empty-path"));
+
+        canvas.edges_mut().push(Edge::new(
+            "self-loop".to_string(),
+            Terminal::new("empty-path".to_string(), None, None),
+            Terminal::new("empty-path".to_string(), None, None),
+            None,
+            None,
+        ));
+        canvas.edges_mut().push(Edge::new(
+            "self-loop".to_string(),
+            Terminal::new("empty-path".to_string(), None, None),
+            Terminal::new("ghost".to_string(), None, None),
+            None,
+            None,
+        ));
+
+        let issues = canvas.validate();
+        assert!(issues.contains(&ValidationIssue::DuplicateNodeId("empty-path".to_string())));
+        assert!(issues.contains(&ValidationIssue::ZeroDimension {
+            node_id: "empty-path".to_string()
+        }));
+        assert!(issues.contains(&ValidationIssue::EmptyFilePath {
+            node_id: "empty-path".to_string()
+        }));
+        assert!(issues.contains(&ValidationIssue::DuplicateEdgeId("self-loop".to_string())));
+        assert!(issues.contains(&ValidationIssue::SelfLoop {
+            edge_id: "self-loop".to_string()
+        }));
+        assert!(issues.contains(&ValidationIssue::DanglingEdge {
+            edge_id: "self-loop".to_string(),
+            missing_node: "ghost".to_string()
+        }));
+    }
+
+    #[test]
+    fn validate_self_loop_on_missing_node_reports_once() {
+        let mut canvas = Canvas::default();
+        canvas.edges_mut().push(Edge::new(
+            "ghost-loop".to_string(),
+            Terminal::new("ghost".to_string(), None, None),
+            Terminal::new("ghost".to_string(), None, None),
+            None,
+            None,
+        ));
+
+        let issues = canvas.validate();
+        let dangling_count = issues
+            .iter()
+            .filter(|i| {
+                matches!(i, ValidationIssue::DanglingEdge { missing_node, .. } if missing_node == "ghost")
+            })
+            .count();
+        assert_eq!(dangling_count, 1);
+    }
 }
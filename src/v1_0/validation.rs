@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use super::{Canvas, EdgeId, GenericNodeInfo, Node, NodeId};
+
+/// A structural problem found by [Canvas::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    DanglingEdge { edge_id: EdgeId, missing_node: NodeId },
+    DuplicateNodeId(NodeId),
+    DuplicateEdgeId(EdgeId),
+    SelfLoop { edge_id: EdgeId },
+    /// A node's width or height is zero.
+    ZeroDimension { node_id: NodeId },
+    /// A `FileNode` with an empty `file` path.
+    EmptyFilePath { node_id: NodeId },
+}
+
+impl Canvas {
+    /// Lint the canvas for structural problems beyond [Canvas::unknown_nodes]: duplicate
+    /// ids, self-loops, zero-sized nodes, and empty file references.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let unknown = self.unknown_nodes();
+        let mut issues = Vec::new();
+
+        let mut seen_node_ids = HashSet::new();
+        for node in self.nodes() {
+            if !seen_node_ids.insert(node.id().as_str()) {
+                issues.push(ValidationIssue::DuplicateNodeId(node.id().clone()));
+            }
+
+            let dims = node.dimensions();
+            if dims.width == 0 || dims.height == 0 {
+                issues.push(ValidationIssue::ZeroDimension {
+                    node_id: node.id().clone(),
+                });
+            }
+
+            if let Node::File(f) = node {
+                if f.file().as_os_str().is_empty() {
+                    issues.push(ValidationIssue::EmptyFilePath {
+                        node_id: node.id().clone(),
+                    });
+                }
+            }
+        }
+
+        let mut seen_edge_ids = HashSet::new();
+        for edge in &self.edges {
+            if !seen_edge_ids.insert(edge.id().as_str()) {
+                issues.push(ValidationIssue::DuplicateEdgeId(edge.id().clone()));
+            }
+
+            if edge.from_node() == edge.to_node() {
+                issues.push(ValidationIssue::SelfLoop {
+                    edge_id: edge.id().clone(),
+                });
+            }
+
+            if unknown.contains(edge.from_node().as_str()) {
+                issues.push(ValidationIssue::DanglingEdge {
+                    edge_id: edge.id().clone(),
+                    missing_node: edge.from_node().clone(),
+                });
+            }
+            // Only report the `to_node` separately if it's a different id, otherwise a
+            // self-loop onto a missing node would be reported twice.
+            if edge.to_node() != edge.from_node() && unknown.contains(edge.to_node().as_str()) {
+                issues.push(ValidationIssue::DanglingEdge {
+                    edge_id: edge.id().clone(),
+                    missing_node: edge.to_node().clone(),
+                });
+            }
+        }
+
+        issues
+    }
+}